@@ -1,11 +1,16 @@
+use std::char;
 use std::collections::HashMap;
+use std::fmt;
+use std::ops;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Json {
     Object(HashMap<String, Json>),
     Array(Vec<Json>),
     String(String),
-    Number(usize),
+    I64(i64),
+    U64(u64),
+    F64(f64),
     Boolean(bool),
     Null
 }
@@ -21,34 +26,247 @@ impl Json {
         let builder = JsonBuilder::new(input.chars());
         builder.build()
     }
+
+    /// Like `from_str`, but rejects grammar violations `from_str` silently
+    /// tolerates: leading/doubled/trailing commas and missing value
+    /// separators in arrays and objects.
+    pub fn from_str_strict(input: &str) -> Result<Json, JsonError> {
+        let mut builder = JsonBuilder::new(input.chars());
+        builder.strict = true;
+        builder.build()
+    }
+
+    /// Pretty-prints the value, indenting nested objects/arrays by `indent`
+    /// spaces per level and putting each element on its own line.
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write(&mut out, Some(indent), 0);
+        out
+    }
+
+    fn write(&self, out: &mut String, indent: Option<usize>, depth: usize) {
+        match *self {
+            Json::Object(ref map) => write_object(map, out, indent, depth),
+            Json::Array(ref vec) => write_array(vec, out, indent, depth),
+            Json::String(ref s) => write_escaped_string(s, out),
+            Json::I64(n) => out.push_str(&n.to_string()),
+            Json::U64(n) => out.push_str(&n.to_string()),
+            Json::F64(n) => out.push_str(&n.to_string()),
+            Json::Boolean(b) => out.push_str(if b { "true" } else { "false" }),
+            Json::Null => out.push_str("null")
+        }
+    }
+
+    /// Looks up `key` if this value is an object, returning `None`
+    /// otherwise or if the key is absent.
+    pub fn find(&self, key: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref map) => map.get(key),
+            _ => None
+        }
+    }
+
+    /// Follows a chain of object keys, stopping as soon as one is
+    /// missing or the value at that point isn't an object.
+    pub fn find_path<'a>(&'a self, keys: &[&str]) -> Option<&'a Json> {
+        let mut target = self;
+        for key in keys {
+            match target.find(key) {
+                Some(value) => target = value,
+                None => return None
+            }
+        }
+        Some(target)
+    }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match *self {
+            Json::String(ref s) => Some(s),
+            _ => None
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Json>> {
+        match *self {
+            Json::Array(ref v) => Some(v),
+            _ => None
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&HashMap<String, Json>> {
+        match *self {
+            Json::Object(ref m) => Some(m),
+            _ => None
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Json::Boolean(b) => Some(b),
+            _ => None
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        match *self {
+            Json::Null => true,
+            _ => false
+        }
+    }
 }
 
-struct JsonBuilder<T> {
+impl<'a> ops::Index<&'a str> for Json {
+    type Output = Json;
+
+    fn index(&self, idx: &'a str) -> &Json {
+        self.find(idx).unwrap_or_else(|| panic!("no key found for index {:?}", idx))
+    }
+}
+
+impl ops::Index<usize> for Json {
+    type Output = Json;
+
+    fn index(&self, idx: usize) -> &Json {
+        match *self {
+            Json::Array(ref v) => &v[idx],
+            _ => panic!("can only index Json with usize if it is an array")
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, None, 0);
+        f.write_str(&out)
+    }
+}
+
+fn write_indent(out: &mut String, indent: Option<usize>, depth: usize) {
+    if let Some(width) = indent {
+        out.push('\n');
+        for _ in 0 .. width * depth {
+            out.push(' ');
+        }
+    }
+}
+
+fn write_array(vec: &Vec<Json>, out: &mut String, indent: Option<usize>, depth: usize) {
+    out.push('[');
+    let mut first = true;
+    for item in vec {
+        if !first {
+            out.push(',');
+        }
+        first = false;
+        write_indent(out, indent, depth + 1);
+        item.write(out, indent, depth + 1);
+    }
+    if !vec.is_empty() {
+        write_indent(out, indent, depth);
+    }
+    out.push(']');
+}
+
+fn write_object(map: &HashMap<String, Json>, out: &mut String, indent: Option<usize>, depth: usize) {
+    out.push('{');
+    let mut first = true;
+    for (key, value) in map {
+        if !first {
+            out.push(',');
+        }
+        first = false;
+        write_indent(out, indent, depth + 1);
+        write_escaped_string(key, out);
+        out.push(':');
+        if indent.is_some() {
+            out.push(' ');
+        }
+        value.write(out, indent, depth + 1);
+    }
+    if !map.is_empty() {
+        write_indent(out, indent, depth);
+    }
+    out.push('}');
+}
+
+fn write_escaped_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+}
+
+/// One step of a streaming JSON parse, as produced by `Parser`.
+#[derive(Debug, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    StringValue(String),
+    NumberValue(f64),
+    BooleanValue(bool),
+    NullValue,
+    Error(JsonError)
+}
+
+/// A single frame of the path from the document root down to wherever
+/// `Parser` currently is, as exposed by `Parser::stack`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StackElement {
+    Key(String),
+    Index(usize)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ParserState {
+    StartValue,
+    ArrayFirst,
+    ArrayNext,
+    ObjectFirst,
+    ObjectNext
+}
+
+/// Low-level character reader shared by `JsonBuilder` and `Parser`: tracks
+/// the current token plus one token of lookahead, and decodes quoted
+/// strings and their escape sequences (including `\uXXXX` and UTF-16
+/// surrogate pairs), so that logic is written and tested exactly once.
+struct Reader<T> {
     iter: T,
     token: Option<char>,
+    peeked: Option<char>,
     column: usize,
-    line: usize,
-    eof_allowed: bool
+    line: usize
 }
 
-impl<T: Iterator<Item = char>> JsonBuilder<T> {
-    fn new(iter: T) -> JsonBuilder<T> {
-        JsonBuilder {
+impl<T: Iterator<Item = char>> Reader<T> {
+    fn new(iter: T) -> Reader<T> {
+        Reader {
             iter: iter,
             token: None,
+            peeked: None,
             column: 0,
-            line: 0,
-            eof_allowed: true
+            line: 0
         }
     }
 
-    fn build(mut self) -> Result<Json, JsonError> {
-        self.next();
-        self.parse()
-    }
-
     fn next(&mut self) -> Option<char> {
-        self.token = self.iter.next();
+        self.token = match self.peeked.take() {
+            Some(c) => Some(c),
+            None => self.iter.next()
+        };
         match self.token {
             Some('\n') => {
                 self.line += 1;
@@ -61,49 +279,677 @@ impl<T: Iterator<Item = char>> JsonBuilder<T> {
         self.token
     }
 
-    fn parse(&mut self) -> Result<Json, JsonError> {
-        self.parse_whitespace();
-        match self.token {
-            Some('n') => self.parse_ident("ull", Json::Null),
-            Some('t') => self.parse_ident("rue", Json::Boolean(true)),
-            Some('f') => self.parse_ident("alse", Json::Boolean(false)),
-            Some('"') => self.parse_string(),
-            Some('[') => self.parse_list(),
-            Some('{') => self.parse_object(),
-            Some('0' ... '9') => self.parse_number(),
-            Some(_) => Err(JsonError::ParseError(format!("unexpected character ({:?}) at line: {:?}, column: {:?} ", self.token, self.line, self.column))),
-            None => Err(JsonError::NotImplemented)
+    fn peek(&mut self) -> Option<char> {
+        if self.peeked.is_none() {
+            self.peeked = self.iter.next();
         }
+        self.peeked
     }
 
-    fn parse_number(&mut self) -> Result<Json, JsonError> {
+    // Accumulates a JSON number token starting at the current character,
+    // returning its raw text and whether it contains a `.`/`e`/`E` (and so
+    // must be parsed as a float rather than an integer). Leaves the token
+    // on the number's last character, and errors if anything other than a
+    // space, a newline, EOF, `,`, `]` or `}` follows it, so a malformed
+    // suffix like `123abc` can't be silently dropped.
+    fn parse_number_raw(&mut self) -> Result<(String, bool), JsonError> {
         let mut num = match self.token {
             Some(d) => d.to_string(),
             None => return Err(JsonError::ParseError("Unexpected eof.".to_string()))
         };
 
+        let mut is_float = false;
+
+        loop {
+            match self.peek() {
+                Some(d @ '0' ... '9') => {
+                    num.push(d);
+                    self.next();
+                }
+                Some(d @ '.') if !is_float => {
+                    is_float = true;
+                    num.push(d);
+                    self.next();
+                }
+                Some(d @ 'e') | Some(d @ 'E') => {
+                    is_float = true;
+                    num.push(d);
+                    self.next();
+                    if let Some(s @ '+') | Some(s @ '-') = self.peek() {
+                        num.push(s);
+                        self.next();
+                    }
+                }
+                _ => break
+            }
+        }
+
+        match self.peek() {
+            Some(' ') | Some('\n') | Some(',') | Some(']') | Some('}') | None => Ok((num, is_float)),
+            Some(c) => Err(JsonError::ParseError(format!("unexpected character ({:?}) after number at line: {}, column: {}.", c, self.line, self.column)))
+        }
+    }
+
+    fn parse_string_raw(&mut self) -> Result<String, JsonError> {
+        let mut string = String::new();
         loop {
             self.next();
             match self.token {
-                Some(d @ '0' ... '9') => num.push(d),
-                Some(',') => break,
-                Some(_) => return Err(JsonError::ParseError(format!("unexpected character ({:?}) at line: {:?}, column: {:?} ", self.token, self.line, self.column))),
-                None => {
-                    if self.eof_allowed {
-                        break;
-                    } else {
-                        return Err(JsonError::ParseError("Unexpected eof.".to_string()));
-                    }
+                Some('"') => break,
+                Some('\\') => {
+                    let c = try!(self.parse_escape());
+                    string.push(c);
                 }
+                Some(c) => string.push(c),
+                None => return Err(JsonError::ParseError("Unexpected eof.".to_string()))
+            }
+        }
+
+        Ok(string)
+    }
+
+    fn parse_escape(&mut self) -> Result<char, JsonError> {
+        self.next();
+        match self.token {
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('/') => Ok('/'),
+            Some('n') => Ok('\n'),
+            Some('t') => Ok('\t'),
+            Some('r') => Ok('\r'),
+            Some('b') => Ok('\u{8}'),
+            Some('f') => Ok('\u{c}'),
+            Some('u') => self.parse_unicode_escape(),
+            Some(_) => Err(JsonError::ParseError(format!("escape error at line: {:?}, column: {:?}.", self.line, self.column))),
+            None => Err(JsonError::ParseError("Unexpected eof.".to_string()))
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        let mut value = 0u32;
+        for _ in 0 .. 4 {
+            let digit = match self.next() {
+                Some(c) => c.to_digit(16),
+                None => None
+            };
+            match digit {
+                Some(d) => value = value * 16 + d,
+                None => return Err(JsonError::ParseError(format!("invalid \\u escape at line: {:?}, column: {:?}.", self.line, self.column)))
+            }
+        }
+        Ok(value)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, JsonError> {
+        let hi = try!(self.parse_hex4());
+
+        if hi >= 0xD800 && hi <= 0xDBFF {
+            if self.next() != Some('\\') || self.next() != Some('u') {
+                return Err(JsonError::ParseError(format!("expected low surrogate \\u escape at line: {:?}, column: {:?}.", self.line, self.column)));
             }
+
+            let lo = try!(self.parse_hex4());
+            if lo < 0xDC00 || lo > 0xDFFF {
+                return Err(JsonError::ParseError(format!("invalid low surrogate at line: {:?}, column: {:?}.", self.line, self.column)));
+            }
+
+            let code_point = 0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00);
+            char::from_u32(code_point).ok_or_else(|| JsonError::ParseError(format!("invalid \\u escape at line: {:?}, column: {:?}.", self.line, self.column)))
+        } else if hi >= 0xDC00 && hi <= 0xDFFF {
+            Err(JsonError::ParseError(format!("unpaired low surrogate at line: {:?}, column: {:?}.", self.line, self.column)))
+        } else {
+            char::from_u32(hi).ok_or_else(|| JsonError::ParseError(format!("invalid \\u escape at line: {:?}, column: {:?}.", self.line, self.column)))
+        }
+    }
+}
+
+/// A pull-parser that yields `JsonEvent`s lazily instead of building a
+/// whole `Json` tree up front, so large documents can be processed
+/// without allocating the full value in memory.
+pub struct Parser<T> {
+    reader: Reader<T>,
+    state_stack: Vec<ParserState>,
+    stack: Vec<StackElement>
+}
+
+impl<T: Iterator<Item = char>> Parser<T> {
+    pub fn new(iter: T) -> Parser<T> {
+        Parser {
+            reader: Reader::new(iter),
+            state_stack: vec![ParserState::StartValue],
+            stack: Vec::new()
+        }
+    }
+
+    /// The path of keys/indices from the root down to the value most
+    /// recently produced (or currently being produced).
+    pub fn stack(&self) -> &[StackElement] {
+        &self.stack
+    }
+
+    fn read_char(&mut self) -> Option<char> {
+        self.reader.next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.reader.token == Some(' ') || self.reader.token == Some('\n') {
+            self.read_char();
         }
+    }
+
+    fn error(&mut self, msg: String) -> JsonEvent {
+        self.error_from(JsonError::ParseError(msg))
+    }
+
+    fn error_from(&mut self, err: JsonError) -> JsonEvent {
+        self.state_stack.clear();
+        JsonEvent::Error(err)
+    }
+
+    fn parse_ident_raw(&mut self, ident: &str) -> bool {
+        ident.chars().all(|c| Some(c) == self.read_char())
+    }
 
-        match num.parse::<usize>() {
-            Ok(num) => Ok(Json::Number(num)),
+    fn parse_number_raw(&mut self) -> Result<f64, JsonError> {
+        let (num, _) = try!(self.reader.parse_number_raw());
+        match num.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(n),
+            Ok(_) => Err(JsonError::ParseError(format!("number out of range: {}", num))),
             Err(_) => Err(JsonError::ParseError(format!("Couldn't parse number: {}", num)))
         }
     }
 
+    // Reads a `"key":` pair, leaving the token on the first character
+    // of the value that follows the colon.
+    fn read_key_and_colon(&mut self) -> Result<String, JsonEvent> {
+        let key = match self.reader.parse_string_raw() {
+            Ok(key) => key,
+            Err(err) => return Err(self.error_from(err))
+        };
+
+        self.read_char();
+        self.skip_whitespace();
+        if self.reader.token != Some(':') {
+            return Err(self.error(format!("Expected ':' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)));
+        }
+
+        self.read_char();
+        self.skip_whitespace();
+        Ok(key)
+    }
+
+    // Parses whatever value starts at the current token and returns the
+    // matching event, pushing a follow-up `ParserState` for arrays/objects
+    // so the next call to `next()` resumes where this one left off.
+    fn parse_value_start(&mut self) -> JsonEvent {
+        match self.reader.token {
+            Some('n') => {
+                if self.parse_ident_raw("ull") {
+                    JsonEvent::NullValue
+                } else {
+                    self.error("invalid literal, expected 'null'.".to_string())
+                }
+            }
+            Some('t') => {
+                if self.parse_ident_raw("rue") {
+                    JsonEvent::BooleanValue(true)
+                } else {
+                    self.error("invalid literal, expected 'true'.".to_string())
+                }
+            }
+            Some('f') => {
+                if self.parse_ident_raw("alse") {
+                    JsonEvent::BooleanValue(false)
+                } else {
+                    self.error("invalid literal, expected 'false'.".to_string())
+                }
+            }
+            Some('"') => match self.reader.parse_string_raw() {
+                Ok(s) => JsonEvent::StringValue(s),
+                Err(e) => self.error_from(e)
+            },
+            Some('0' ... '9') | Some('-') => match self.parse_number_raw() {
+                Ok(n) => JsonEvent::NumberValue(n),
+                Err(e) => self.error_from(e)
+            },
+            Some('[') => {
+                self.state_stack.push(ParserState::ArrayFirst);
+                JsonEvent::ArrayStart
+            }
+            Some('{') => {
+                self.state_stack.push(ParserState::ObjectFirst);
+                JsonEvent::ObjectStart
+            }
+            _ => self.error(format!("unexpected character ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column))
+        }
+    }
+}
+
+impl<T: Iterator<Item = char>> Iterator for Parser<T> {
+    type Item = JsonEvent;
+
+    fn next(&mut self) -> Option<JsonEvent> {
+        let state = match self.state_stack.pop() {
+            Some(state) => state,
+            None => return None
+        };
+
+        match state {
+            ParserState::StartValue => {
+                self.read_char();
+                self.skip_whitespace();
+                Some(self.parse_value_start())
+            }
+            ParserState::ArrayFirst => {
+                self.read_char();
+                self.skip_whitespace();
+                if self.reader.token == Some(']') {
+                    return Some(JsonEvent::ArrayEnd);
+                }
+                self.stack.push(StackElement::Index(0));
+                self.state_stack.push(ParserState::ArrayNext);
+                Some(self.parse_value_start())
+            }
+            ParserState::ArrayNext => {
+                self.read_char();
+                self.skip_whitespace();
+                match self.reader.token {
+                    Some(']') => {
+                        self.stack.pop();
+                        Some(JsonEvent::ArrayEnd)
+                    }
+                    Some(',') => {
+                        if let Some(&mut StackElement::Index(ref mut i)) = self.stack.last_mut() {
+                            *i += 1;
+                        }
+                        self.read_char();
+                        self.skip_whitespace();
+                        self.state_stack.push(ParserState::ArrayNext);
+                        Some(self.parse_value_start())
+                    }
+                    _ => Some(self.error(format!("expected ',' or ']' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)))
+                }
+            }
+            ParserState::ObjectFirst => {
+                self.read_char();
+                self.skip_whitespace();
+                if self.reader.token == Some('}') {
+                    return Some(JsonEvent::ObjectEnd);
+                }
+                match self.reader.token {
+                    Some('"') => match self.read_key_and_colon() {
+                        Ok(key) => {
+                            self.stack.push(StackElement::Key(key));
+                            self.state_stack.push(ParserState::ObjectNext);
+                            Some(self.parse_value_start())
+                        }
+                        Err(e) => Some(e)
+                    },
+                    _ => Some(self.error(format!("expected '\"' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)))
+                }
+            }
+            ParserState::ObjectNext => {
+                self.read_char();
+                self.skip_whitespace();
+                match self.reader.token {
+                    Some('}') => {
+                        self.stack.pop();
+                        Some(JsonEvent::ObjectEnd)
+                    }
+                    Some(',') => {
+                        self.stack.pop();
+                        self.read_char();
+                        self.skip_whitespace();
+                        match self.reader.token {
+                            Some('"') => match self.read_key_and_colon() {
+                                Ok(key) => {
+                                    self.stack.push(StackElement::Key(key));
+                                    self.state_stack.push(ParserState::ObjectNext);
+                                    Some(self.parse_value_start())
+                                }
+                                Err(e) => Some(e)
+                            },
+                            _ => Some(self.error(format!("expected '\"' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)))
+                        }
+                    }
+                    _ => Some(self.error(format!("expected ',' or '}}' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)))
+                }
+            }
+        }
+    }
+}
+
+/// An error produced while decoding a `Json` value into a concrete type.
+#[derive(Debug, PartialEq)]
+pub enum DecoderError {
+    ExpectedError(String, String),
+    MissingFieldError(String)
+}
+
+fn expected<T>(want: &str, got: &Json) -> Result<T, DecoderError> {
+    Err(DecoderError::ExpectedError(want.to_string(), got.to_string()))
+}
+
+/// Types that can be built from a `Json` value via a `Decoder`.
+pub trait Decodable: Sized {
+    fn decode(d: &mut Decoder) -> Result<Self, DecoderError>;
+}
+
+/// Decodes a `Json` value into any type implementing `Decodable`.
+pub fn decode<T: Decodable>(json: &Json) -> Result<T, DecoderError> {
+    let mut decoder = Decoder::new(json.clone());
+    Decodable::decode(&mut decoder)
+}
+
+/// Walks a `Json` value, handing out its pieces as the `Decodable` impls
+/// for the target type ask for them.
+pub struct Decoder {
+    stack: Vec<Json>
+}
+
+impl Decoder {
+    pub fn new(json: Json) -> Decoder {
+        Decoder { stack: vec![json] }
+    }
+
+    fn pop(&mut self) -> Json {
+        self.stack.pop().expect("nothing left to decode")
+    }
+
+    pub fn read_nil(&mut self) -> Result<(), DecoderError> {
+        match self.pop() {
+            Json::Null => Ok(()),
+            other => expected("Null", &other)
+        }
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, DecoderError> {
+        match self.pop() {
+            Json::Boolean(b) => Ok(b),
+            other => expected("Boolean", &other)
+        }
+    }
+
+    pub fn read_str(&mut self) -> Result<String, DecoderError> {
+        match self.pop() {
+            Json::String(s) => Ok(s),
+            other => expected("String", &other)
+        }
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, DecoderError> {
+        match self.pop() {
+            Json::U64(n) => Ok(n),
+            Json::I64(n) if n >= 0 => Ok(n as u64),
+            other => expected("Number", &other)
+        }
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, DecoderError> {
+        match self.pop() {
+            Json::I64(n) => Ok(n),
+            Json::U64(n) => Ok(n as i64),
+            other => expected("Number", &other)
+        }
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64, DecoderError> {
+        match self.pop() {
+            Json::F64(n) => Ok(n),
+            Json::I64(n) => Ok(n as f64),
+            Json::U64(n) => Ok(n as f64),
+            other => expected("Number", &other)
+        }
+    }
+
+    pub fn read_option<T, F>(&mut self, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder, bool) -> Result<T, DecoderError>
+    {
+        match self.pop() {
+            Json::Null => f(self, false),
+            value => {
+                self.stack.push(value);
+                f(self, true)
+            }
+        }
+    }
+
+    pub fn read_seq<T, F>(&mut self, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder, usize) -> Result<T, DecoderError>
+    {
+        let array = match self.pop() {
+            Json::Array(a) => a,
+            other => return expected("Array", &other)
+        };
+        let len = array.len();
+        for item in array.into_iter().rev() {
+            self.stack.push(item);
+        }
+        f(self, len)
+    }
+
+    pub fn read_seq_elt<T, F>(&mut self, _idx: usize, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder) -> Result<T, DecoderError>
+    {
+        f(self)
+    }
+
+    pub fn read_map<T, F>(&mut self, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder, usize) -> Result<T, DecoderError>
+    {
+        let obj = match self.pop() {
+            Json::Object(map) => map,
+            other => return expected("Object", &other)
+        };
+        let len = obj.len();
+        for (key, value) in obj {
+            self.stack.push(value);
+            self.stack.push(Json::String(key));
+        }
+        f(self, len)
+    }
+
+    pub fn read_map_elt_key<T, F>(&mut self, _idx: usize, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder) -> Result<T, DecoderError>
+    {
+        f(self)
+    }
+
+    pub fn read_map_elt_val<T, F>(&mut self, _idx: usize, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder) -> Result<T, DecoderError>
+    {
+        f(self)
+    }
+
+    /// Wraps a `Decodable` impl that reads a struct's fields with
+    /// `read_struct_field`, discarding the object those calls leave on the
+    /// stack together with any fields the impl did not read.
+    pub fn read_struct<T, F>(&mut self, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder) -> Result<T, DecoderError>
+    {
+        let value = try!(f(self));
+        self.pop();
+        Ok(value)
+    }
+
+    pub fn read_struct_field<T, F>(&mut self, name: &str, f: F) -> Result<T, DecoderError>
+        where F: FnOnce(&mut Decoder) -> Result<T, DecoderError>
+    {
+        let mut obj = match self.pop() {
+            Json::Object(map) => map,
+            other => return expected("Object", &other)
+        };
+
+        let field_present = obj.contains_key(name);
+        self.stack.push(obj.remove(name).unwrap_or(Json::Null));
+        let result = f(self);
+        self.stack.push(Json::Object(obj));
+
+        match result {
+            Ok(value) => Ok(value),
+            Err(err) => {
+                if field_present {
+                    Err(err)
+                } else {
+                    Err(DecoderError::MissingFieldError(name.to_string()))
+                }
+            }
+        }
+    }
+}
+
+impl Decodable for bool {
+    fn decode(d: &mut Decoder) -> Result<bool, DecoderError> {
+        d.read_bool()
+    }
+}
+
+impl Decodable for String {
+    fn decode(d: &mut Decoder) -> Result<String, DecoderError> {
+        d.read_str()
+    }
+}
+
+impl Decodable for u64 {
+    fn decode(d: &mut Decoder) -> Result<u64, DecoderError> {
+        d.read_u64()
+    }
+}
+
+impl Decodable for i64 {
+    fn decode(d: &mut Decoder) -> Result<i64, DecoderError> {
+        d.read_i64()
+    }
+}
+
+impl Decodable for f64 {
+    fn decode(d: &mut Decoder) -> Result<f64, DecoderError> {
+        d.read_f64()
+    }
+}
+
+impl Decodable for f32 {
+    fn decode(d: &mut Decoder) -> Result<f32, DecoderError> {
+        Ok(try!(d.read_f64()) as f32)
+    }
+}
+
+macro_rules! impl_decodable_for_int {
+    ($ty:ty, $read:ident) => {
+        impl Decodable for $ty {
+            fn decode(d: &mut Decoder) -> Result<$ty, DecoderError> {
+                Ok(try!(d.$read()) as $ty)
+            }
+        }
+    }
+}
+
+impl_decodable_for_int!(u8, read_u64);
+impl_decodable_for_int!(u16, read_u64);
+impl_decodable_for_int!(u32, read_u64);
+impl_decodable_for_int!(usize, read_u64);
+impl_decodable_for_int!(i8, read_i64);
+impl_decodable_for_int!(i16, read_i64);
+impl_decodable_for_int!(i32, read_i64);
+impl_decodable_for_int!(isize, read_i64);
+
+impl<T: Decodable> Decodable for Option<T> {
+    fn decode(d: &mut Decoder) -> Result<Option<T>, DecoderError> {
+        d.read_option(|d, has_value| {
+            if has_value {
+                Ok(Some(try!(Decodable::decode(d))))
+            } else {
+                Ok(None)
+            }
+        })
+    }
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+    fn decode(d: &mut Decoder) -> Result<Vec<T>, DecoderError> {
+        d.read_seq(|d, len| {
+            let mut vec = Vec::with_capacity(len);
+            for i in 0 .. len {
+                vec.push(try!(d.read_seq_elt(i, Decodable::decode)));
+            }
+            Ok(vec)
+        })
+    }
+}
+
+impl<T: Decodable> Decodable for HashMap<String, T> {
+    fn decode(d: &mut Decoder) -> Result<HashMap<String, T>, DecoderError> {
+        d.read_map(|d, len| {
+            let mut map = HashMap::with_capacity(len);
+            for i in 0 .. len {
+                let key: String = try!(d.read_map_elt_key(i, Decodable::decode));
+                let value: T = try!(d.read_map_elt_val(i, Decodable::decode));
+                map.insert(key, value);
+            }
+            Ok(map)
+        })
+    }
+}
+
+struct JsonBuilder<T> {
+    reader: Reader<T>,
+    strict: bool
+}
+
+impl<T: Iterator<Item = char>> JsonBuilder<T> {
+    fn new(iter: T) -> JsonBuilder<T> {
+        JsonBuilder {
+            reader: Reader::new(iter),
+            strict: false
+        }
+    }
+
+    fn build(mut self) -> Result<Json, JsonError> {
+        self.next();
+        self.parse()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        self.reader.next()
+    }
+
+    fn parse(&mut self) -> Result<Json, JsonError> {
+        self.parse_whitespace();
+        match self.reader.token {
+            Some('n') => self.parse_ident("ull", Json::Null),
+            Some('t') => self.parse_ident("rue", Json::Boolean(true)),
+            Some('f') => self.parse_ident("alse", Json::Boolean(false)),
+            Some('"') => self.parse_string(),
+            Some('[') => self.parse_list(),
+            Some('{') => self.parse_object(),
+            Some('0' ... '9') | Some('-') => self.parse_number(),
+            Some(_) => Err(JsonError::ParseError(format!("unexpected character ({:?}) at line: {:?}, column: {:?} ", self.reader.token, self.reader.line, self.reader.column))),
+            None => Err(JsonError::NotImplemented)
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Json, JsonError> {
+        let (num, is_float) = try!(self.reader.parse_number_raw());
+
+        if is_float {
+            match num.parse::<f64>() {
+                Ok(n) if n.is_finite() => Ok(Json::F64(n)),
+                Ok(_) => Err(JsonError::ParseError(format!("number out of range: {}", num))),
+                Err(_) => Err(JsonError::ParseError(format!("Couldn't parse number: {}", num)))
+            }
+        } else if num.starts_with('-') {
+            match num.parse::<i64>() {
+                Ok(num) => Ok(Json::I64(num)),
+                Err(_) => Err(JsonError::ParseError(format!("Couldn't parse number: {}", num)))
+            }
+        } else {
+            match num.parse::<u64>() {
+                Ok(num) => Ok(Json::U64(num)),
+                Err(_) => Err(JsonError::ParseError(format!("Couldn't parse number: {}", num)))
+            }
+        }
+    }
+
     fn parse_ident(&mut self, ident: &str, res: Json) -> Result<Json, JsonError> {
         if ident.chars().all(|c| Some(c) == self.next()) {
             Ok(res)
@@ -113,48 +959,42 @@ impl<T: Iterator<Item = char>> JsonBuilder<T> {
     }
 
     fn parse_string(&mut self) -> Result<Json, JsonError> {
-        match self.parse_string_raw() {
+        match self.reader.parse_string_raw() {
             Ok(string) => Ok(Json::String(string)),
             Err(err) => Err(err)
         }
     }
 
-    fn parse_string_raw(&mut self) -> Result<String, JsonError> {
-        let mut string = "".to_string();
-        let mut escape = false;
-        loop {
-            self.next();
-            if escape {
-                match self.token {
-                    Some('"') => string.push('"'),
-                    Some('\\') => string.push('\\'),
-                    Some(_) => return Err(JsonError::ParseError("escape error.".to_string())),
-                    None => return Err(JsonError::ParseError("Unexpected eof.".to_string()))
-                }
-                escape = false;
-            } else {
-                match self.token {
-                    Some('"') => break,
-                    Some('\\') => escape = true,
-                    Some(c @ _) => string.push(c),
-                    None => return Err(JsonError::ParseError("Unexpected eof.".to_string()))
-                }
-            }
-        }
-
-        Ok(string)
-    }
-
     fn parse_list(&mut self) -> Result<Json, JsonError> {
-        self.eof_allowed = false;
         let mut list = Vec::new();
+        let mut expect_value = true;
+
         loop {
             self.next();
-            match self.token {
-                Some(']') => break,
-                Some(',') => (),
-                Some(_) => list.push(try!(self.parse())),
-                _ => return Err(JsonError::ParseError(format!("Unexpected character ({:?}) at line: {}, column: {}.", self.token, self.line, self.column)))
+            self.parse_whitespace();
+            match self.reader.token {
+                Some(']') => {
+                    if self.strict && expect_value && !list.is_empty() {
+                        return Err(JsonError::ParseError(format!("unexpected trailing ',' before ']' at line: {}, column: {}.", self.reader.line, self.reader.column)));
+                    }
+                    break;
+                }
+                Some(',') => {
+                    if self.strict {
+                        if expect_value {
+                            return Err(JsonError::ParseError(format!("unexpected ',' at line: {}, column: {}.", self.reader.line, self.reader.column)));
+                        }
+                        expect_value = true;
+                    }
+                }
+                Some(_) => {
+                    if self.strict && !expect_value {
+                        return Err(JsonError::ParseError(format!("expected ',' or ']' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)));
+                    }
+                    list.push(try!(self.parse()));
+                    expect_value = false;
+                }
+                _ => return Err(JsonError::ParseError(format!("Unexpected character ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)))
             };
         }
 
@@ -162,23 +1002,39 @@ impl<T: Iterator<Item = char>> JsonBuilder<T> {
     }
 
     fn parse_object(&mut self) -> Result<Json, JsonError> {
-        self.eof_allowed = false;
         let mut map = HashMap::new();
+        let mut expect_pair = true;
 
         loop {
             self.next();
             self.parse_whitespace();
-            match self.token {
-                Some('}') => break,
+            match self.reader.token {
+                Some('}') => {
+                    if self.strict && expect_pair && !map.is_empty() {
+                        return Err(JsonError::ParseError(format!("unexpected trailing ',' before '}}' at line: {}, column: {}.", self.reader.line, self.reader.column)));
+                    }
+                    break;
+                }
                 Some('"') => {
+                    if self.strict && !expect_pair {
+                        return Err(JsonError::ParseError(format!("expected ',' or '}}' but found ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column)));
+                    }
                     let (key, value) = match self.parse_object_key_value() {
                         Ok((key, value)) => (key, value),
                         Err(err) => return Err(err)
                     };
                     map.insert(key, value);
+                    expect_pair = false;
+                },
+                Some(',') => {
+                    if self.strict {
+                        if expect_pair {
+                            return Err(JsonError::ParseError(format!("unexpected ',' at line: {}, column: {}.", self.reader.line, self.reader.column)));
+                        }
+                        expect_pair = true;
+                    }
                 },
-                Some(',') => (),
-                Some(_) => return Err(JsonError::ParseError(format!("Unexpected character ({:?}) at line: {}, column: {}.", self.token, self.line, self.column))),
+                Some(_) => return Err(JsonError::ParseError(format!("Unexpected character ({:?}) at line: {}, column: {}.", self.reader.token, self.reader.line, self.reader.column))),
                 None => return Err(JsonError::ParseError("Unexpected eof.".to_string()))
             };
         }
@@ -187,8 +1043,8 @@ impl<T: Iterator<Item = char>> JsonBuilder<T> {
     }
 
     fn parse_whitespace(&mut self) {
-        while self.token == Some(' ') ||
-              self.token == Some('\n')
+        while self.reader.token == Some(' ') ||
+              self.reader.token == Some('\n')
         {
             self.next();
         }
@@ -210,13 +1066,13 @@ impl<T: Iterator<Item = char>> JsonBuilder<T> {
     }
 
     fn parse_object_key(&mut self) -> Result<String, JsonError> {
-        let key = match self.parse_string_raw() {
+        let key = match self.reader.parse_string_raw() {
             Ok(key) => key,
             Err(err) => return Err(err)
         };
 
         if self.next() != Some(':') {
-            Err(JsonError::ParseError(format!("Expected to find ':', but found: {:?} at line: {:?}, column: {:?}.", self.token, self.line, self.column)))
+            Err(JsonError::ParseError(format!("Expected to find ':', but found: {:?} at line: {:?}, column: {:?}.", self.reader.token, self.reader.line, self.reader.column)))
         } else {
             Ok(key)
         }
@@ -243,40 +1099,83 @@ mod test {
     fn parse_bool_is_false() {
 
         // act
-        let res = Json::from_str("false").unwrap();
+        let res = Json::from_str("false").unwrap();
+
+        // assert
+        assert_eq!(res, Json::Boolean(false));
+    }
+
+    #[test]
+    fn parse_bool_is_true() {
+
+        // act
+        let res = Json::from_str("true").unwrap();
+
+        // assert
+        assert_eq!(res, Json::Boolean(true));
+    }
+
+    #[test]
+    fn parse_string() {
+
+        // act
+        let res = Json::from_str("\"foo\"").unwrap();
+
+        // assert
+        assert_eq!(res, Json::String("foo".to_string()));
+    }
+
+    #[test]
+    fn parse_string_with_escaped_quote() {
+
+        // act
+        let res = Json::from_str("\"fo\\\"o\"").unwrap();
+
+        // assert
+        assert_eq!(res, Json::String("fo\"o".to_string()));
+    }
+
+    #[test]
+    fn parse_string_with_standard_escapes() {
+
+        // act
+        let res = Json::from_str("\"a\\nb\\tc\\rd\\be\\ff\\/g\"").unwrap();
 
         // assert
-        assert_eq!(res, Json::Boolean(false));
+        assert_eq!(res, Json::String("a\nb\tc\rd\u{8}e\u{c}f/g".to_string()));
     }
 
     #[test]
-    fn parse_bool_is_true() {
+    fn parse_string_with_unicode_escape() {
 
         // act
-        let res = Json::from_str("true").unwrap();
+        let res = Json::from_str("\"\\u00e6\"").unwrap();
 
         // assert
-        assert_eq!(res, Json::Boolean(true));
+        assert_eq!(res, Json::String("\u{e6}".to_string()));
     }
 
     #[test]
-    fn parse_string() {
+    fn parse_string_with_surrogate_pair_escape() {
 
         // act
-        let res = Json::from_str("\"foo\"").unwrap();
+        let res = Json::from_str("\"\\ud83d\\ude00\"").unwrap();
 
         // assert
-        assert_eq!(res, Json::String("foo".to_string()));
+        assert_eq!(res, Json::String("\u{1f600}".to_string()));
     }
 
     #[test]
-    fn parse_string_with_escaped_quote() {
+    fn parse_string_with_unpaired_surrogate_is_error() {
 
         // act
-        let res = Json::from_str("\"fo\\\"o\"").unwrap();
+        let res = Json::from_str("\"\\ud83d\"");
 
         // assert
-        assert_eq!(res, Json::String("fo\"o".to_string()));
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
     }
 
     #[test]
@@ -417,6 +1316,439 @@ mod test {
 
 
         // assert
-        assert_eq!(res, Json::Number(9876));
+        assert_eq!(res, Json::U64(9876));
+    }
+
+    #[test]
+    fn parse_negative_number() {
+
+        // act
+        let res = Json::from_str("-42").unwrap();
+
+        // assert
+        assert_eq!(res, Json::I64(-42));
+    }
+
+    #[test]
+    fn parse_float_number() {
+
+        // act
+        let res = Json::from_str("3.14").unwrap();
+
+        // assert
+        assert_eq!(res, Json::F64(3.14));
+    }
+
+    #[test]
+    fn parse_number_with_exponent() {
+
+        // act
+        let res = Json::from_str("1e10").unwrap();
+
+        // assert
+        assert_eq!(res, Json::F64(1e10));
+    }
+
+    #[test]
+    fn parse_number_in_list() {
+
+        // act
+        let res = Json::from_str("[1,2]").unwrap();
+
+        // assert
+        assert_eq!(res, Json::Array(vec![Json::U64(1), Json::U64(2)]));
+    }
+
+    #[test]
+    fn parse_number_rejects_trailing_garbage() {
+
+        // act
+        let res = Json::from_str("123abc");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn parse_number_rejects_non_finite_overflow() {
+
+        // act
+        let res = Json::from_str("1e400");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn to_string_compact() {
+
+        // arrange
+        let json = Json::Array(vec![Json::U64(1), Json::Boolean(true), Json::Null]);
+
+        // act
+        let res = json.to_string();
+
+        // assert
+        assert_eq!(res, "[1,true,null]");
+    }
+
+    #[test]
+    fn to_string_escapes_string() {
+
+        // arrange
+        let json = Json::String("line\n\"quoted\"\ttab".to_string());
+
+        // act
+        let res = json.to_string();
+
+        // assert
+        assert_eq!(res, "\"line\\n\\\"quoted\\\"\\ttab\"");
+    }
+
+    #[test]
+    fn to_pretty_string_indents_nested_array() {
+
+        // arrange
+        let json = Json::Array(vec![Json::U64(1), Json::U64(2)]);
+
+        // act
+        let res = json.to_pretty_string(2);
+
+        // assert
+        assert_eq!(res, "[\n  1,\n  2\n]");
+    }
+
+    #[test]
+    fn to_string_round_trips_through_from_str() {
+
+        // arrange
+        let json = Json::from_str("{\"a\":[1,2.5,null,true],\"b\":\"hi\"}").unwrap();
+
+        // act
+        let res = Json::from_str(&json.to_string()).unwrap();
+
+        // assert
+        assert_eq!(res, json);
+    }
+
+    #[test]
+    fn parser_emits_events_for_nested_object() {
+
+        // arrange
+        let mut parser = Parser::new("{\"a\":[1,2]}".chars());
+
+        // act & assert
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectStart));
+        assert_eq!(parser.next(), Some(JsonEvent::ArrayStart));
+        assert_eq!(parser.next(), Some(JsonEvent::NumberValue(1.0)));
+        assert_eq!(parser.next(), Some(JsonEvent::NumberValue(2.0)));
+        assert_eq!(parser.next(), Some(JsonEvent::ArrayEnd));
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectEnd));
+        assert_eq!(parser.next(), None);
+    }
+
+    #[test]
+    fn parser_exposes_stack_while_iterating() {
+
+        // arrange
+        let mut parser = Parser::new("{\"a\":[1,2]}".chars());
+
+        // act
+        parser.next(); // ObjectStart
+        parser.next(); // ArrayStart
+        parser.next(); // NumberValue(1.0)
+
+        // assert
+        assert_eq!(parser.stack(), &[StackElement::Key("a".to_string()), StackElement::Index(0)]);
+    }
+
+    #[test]
+    fn parser_emits_error_on_trailing_comma() {
+
+        // arrange
+        let mut parser = Parser::new("[1,]".chars());
+
+        // act
+        parser.next(); // ArrayStart
+        parser.next(); // NumberValue(1.0)
+        let res = parser.next();
+
+        // assert
+        match res {
+            Some(JsonEvent::Error(_)) => (),
+            other => panic!("expected an Error event, got {:?}", other)
+        }
+        assert_eq!(parser.next(), None);
+    }
+
+    #[test]
+    fn parser_emits_error_on_number_with_trailing_garbage() {
+
+        // arrange
+        let mut parser = Parser::new("123abc".chars());
+
+        // act
+        let res = parser.next();
+
+        // assert
+        match res {
+            Some(JsonEvent::Error(_)) => (),
+            other => panic!("expected an Error event, got {:?}", other)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Address {
+        city: String,
+        zip: Option<String>
+    }
+
+    impl Decodable for Address {
+        fn decode(d: &mut Decoder) -> Result<Address, DecoderError> {
+            d.read_struct(|d| {
+                Ok(Address {
+                    city: try!(d.read_struct_field("city", Decodable::decode)),
+                    zip: try!(d.read_struct_field("zip", Decodable::decode))
+                })
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Person {
+        name: String,
+        age: u32,
+        addresses: Vec<Address>
+    }
+
+    impl Decodable for Person {
+        fn decode(d: &mut Decoder) -> Result<Person, DecoderError> {
+            d.read_struct(|d| {
+                Ok(Person {
+                    name: try!(d.read_struct_field("name", Decodable::decode)),
+                    age: try!(d.read_struct_field("age", Decodable::decode)),
+                    addresses: try!(d.read_struct_field("addresses", Decodable::decode))
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn decode_struct_with_nested_seq_and_optional_field() {
+
+        // arrange
+        let json = Json::from_str("{\"name\":\"Alice\",\"age\":30,\"addresses\":[{\"city\":\"Oslo\",\"zip\":\"0150\"},{\"city\":\"Bergen\",\"zip\":null}]}").unwrap();
+
+        // act
+        let person: Person = decode(&json).unwrap();
+
+        // assert
+        assert_eq!(person, Person {
+            name: "Alice".to_string(),
+            age: 30,
+            addresses: vec![
+                Address { city: "Oslo".to_string(), zip: Some("0150".to_string()) },
+                Address { city: "Bergen".to_string(), zip: None }
+            ]
+        });
+    }
+
+    #[test]
+    fn decode_reports_expected_error_on_type_mismatch() {
+
+        // arrange
+        let json = Json::from_str("{\"name\":\"Alice\",\"age\":[],\"addresses\":[]}").unwrap();
+
+        // act
+        let res: Result<Person, DecoderError> = decode(&json);
+
+        // assert
+        assert_eq!(res, Err(DecoderError::ExpectedError("Number".to_string(), "[]".to_string())));
+    }
+
+    #[test]
+    fn decode_reports_missing_field_error() {
+
+        // arrange
+        let json = Json::from_str("{\"name\":\"Alice\"}").unwrap();
+
+        // act
+        let res: Result<Person, DecoderError> = decode(&json);
+
+        // assert
+        assert_eq!(res, Err(DecoderError::MissingFieldError("age".to_string())));
+    }
+
+    #[test]
+    fn decode_hashmap() {
+
+        // arrange
+        let json = Json::from_str("{\"a\":1,\"b\":2}").unwrap();
+
+        // act
+        let map: HashMap<String, u64> = decode(&json).unwrap();
+
+        // assert
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), 1);
+        expected.insert("b".to_string(), 2);
+        assert_eq!(map, expected);
+    }
+
+    #[test]
+    fn find_looks_up_a_key_on_an_object() {
+
+        // arrange
+        let json = Json::from_str("{\"name\":\"Alice\"}").unwrap();
+
+        // act & assert
+        assert_eq!(json.find("name"), Some(&Json::String("Alice".to_string())));
+        assert_eq!(json.find("missing"), None);
+    }
+
+    #[test]
+    fn find_path_follows_nested_objects() {
+
+        // arrange
+        let json = Json::from_str("{\"address\":{\"city\":\"Oslo\"}}").unwrap();
+
+        // act & assert
+        assert_eq!(json.find_path(&["address", "city"]), Some(&Json::String("Oslo".to_string())));
+        assert_eq!(json.find_path(&["address", "zip"]), None);
+    }
+
+    #[test]
+    fn as_helpers_unwrap_matching_variants() {
+
+        // arrange
+        let json = Json::from_str("{\"name\":\"Alice\",\"tags\":[],\"active\":true,\"extra\":null}").unwrap();
+
+        // act & assert
+        assert_eq!(json["name"].as_string(), Some("Alice"));
+        assert!(json["tags"].as_array().is_some());
+        assert!(json.as_object().is_some());
+        assert_eq!(json["active"].as_bool(), Some(true));
+        assert!(json["extra"].is_null());
+        assert_eq!(json["name"].as_array(), None);
+    }
+
+    #[test]
+    fn index_navigates_nested_objects_and_arrays() {
+
+        // arrange
+        let json = Json::from_str("{\"address\":{\"city\":\"Oslo\"},\"tags\":[\"a\",\"b\"]}").unwrap();
+
+        // act & assert
+        assert_eq!(json["address"]["city"], Json::String("Oslo".to_string()));
+        assert_eq!(json["tags"][1], Json::String("b".to_string()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_panics_on_missing_key() {
+
+        // arrange
+        let json = Json::from_str("{}").unwrap();
+
+        // act
+        let _ = &json["missing"];
+    }
+
+    #[test]
+    fn lenient_mode_still_accepts_stray_commas() {
+
+        // act
+        let list = Json::from_str("[,,null]").unwrap();
+        let trailing = Json::from_str("[null,,]").unwrap();
+        let obj = Json::from_str("{,}").unwrap();
+
+        // assert
+        assert_eq!(list, Json::Array(vec![Json::Null]));
+        assert_eq!(trailing, Json::Array(vec![Json::Null]));
+        assert_eq!(obj, Json::Object(HashMap::new()));
+    }
+
+    #[test]
+    fn strict_mode_rejects_leading_comma_in_list() {
+
+        // act
+        let res = Json::from_str_strict("[,,null]");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_mode_rejects_trailing_comma_in_list() {
+
+        // act
+        let res = Json::from_str_strict("[null,]");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_mode_rejects_doubled_comma_in_list() {
+
+        // act
+        let res = Json::from_str_strict("[null,,false]");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_mode_rejects_missing_separator() {
+
+        // act
+        let res = Json::from_str_strict("[null false]");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_mode_rejects_empty_object_with_comma() {
+
+        // act
+        let res = Json::from_str_strict("{,}");
+
+        // assert
+        match res {
+            Err(JsonError::ParseError(_)) => (),
+            other => panic!("expected a ParseError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn strict_mode_accepts_well_formed_input() {
+
+        // act
+        let res = Json::from_str_strict("{\"a\":[1,2,3],\"b\":null}").unwrap();
+
+        // assert
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), Json::Array(vec![Json::U64(1), Json::U64(2), Json::U64(3)]));
+        expected.insert("b".to_string(), Json::Null);
+        assert_eq!(res, Json::Object(expected));
     }
 }